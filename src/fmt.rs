@@ -0,0 +1,202 @@
+//! Zero-allocation formatting adapters over the raw 16 bytes of a UUIDv6.
+
+use crate::RawUUIDv6;
+use core::fmt;
+use core::str;
+
+const HEX_LOWER: &[u8; 16] = b"0123456789abcdef";
+const HEX_UPPER: &[u8; 16] = b"0123456789ABCDEF";
+
+fn hex_into(out: &mut [u8], bin: &[u8], table: &[u8; 16]) {
+    let mut j = 0;
+    for b in bin {
+        out[j] = table[(b >> 4) as usize];
+        out[j + 1] = table[(b & 0x0f) as usize];
+        j += 2;
+    }
+}
+
+fn encode_hyphenated(bytes: &[u8; 16], out: &mut [u8], table: &[u8; 16]) {
+    out[8] = b'-';
+    out[13] = b'-';
+    out[18] = b'-';
+    out[23] = b'-';
+    hex_into(&mut out[0..], &bytes[0..4], table);
+    hex_into(&mut out[9..], &bytes[4..6], table);
+    hex_into(&mut out[14..], &bytes[6..8], table);
+    hex_into(&mut out[19..], &bytes[8..10], table);
+    hex_into(&mut out[24..], &bytes[10..], table);
+}
+
+/// The 32-character form, without hyphens: `67e5504410b1426f9247bb680e5fe0c8`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Simple([u8; 16]);
+
+/// The canonical 36-character hyphenated form: `67e55044-10b1-426f-9247-bb680e5fe0c8`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Hyphenated([u8; 16]);
+
+/// The URN form: `urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Urn([u8; 16]);
+
+/// The braced form: `{67e55044-10b1-426f-9247-bb680e5fe0c8}`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Braced([u8; 16]);
+
+impl Simple {
+    /// The length of a simple-formatted UUIDv6 string.
+    pub const LENGTH: usize = 32;
+
+    /// Write the lower-case simple form into `buffer`, returning it as a `&str`.
+    pub fn encode_lower<'buf>(&self, buffer: &'buf mut [u8; Self::LENGTH]) -> &'buf str {
+        hex_into(&mut buffer[..], &self.0, HEX_LOWER);
+        str::from_utf8(&buffer[..]).unwrap()
+    }
+
+    /// Write the upper-case simple form into `buffer`, returning it as a `&str`.
+    pub fn encode_upper<'buf>(&self, buffer: &'buf mut [u8; Self::LENGTH]) -> &'buf str {
+        hex_into(&mut buffer[..], &self.0, HEX_UPPER);
+        str::from_utf8(&buffer[..]).unwrap()
+    }
+}
+
+impl Hyphenated {
+    /// The length of a hyphenated UUIDv6 string.
+    pub const LENGTH: usize = 36;
+
+    /// Wrap raw bytes for hyphenated formatting, for use by other
+    /// same-layout UUID generators in this crate.
+    pub(crate) fn from_bytes(bytes: [u8; 16]) -> Self {
+        Self(bytes)
+    }
+
+    /// Write the lower-case hyphenated form into `buffer`, returning it as a `&str`.
+    pub fn encode_lower<'buf>(&self, buffer: &'buf mut [u8; Self::LENGTH]) -> &'buf str {
+        encode_hyphenated(&self.0, &mut buffer[..], HEX_LOWER);
+        str::from_utf8(&buffer[..]).unwrap()
+    }
+
+    /// Write the upper-case hyphenated form into `buffer`, returning it as a `&str`.
+    pub fn encode_upper<'buf>(&self, buffer: &'buf mut [u8; Self::LENGTH]) -> &'buf str {
+        encode_hyphenated(&self.0, &mut buffer[..], HEX_UPPER);
+        str::from_utf8(&buffer[..]).unwrap()
+    }
+}
+
+impl Urn {
+    /// The length of a URN-formatted UUIDv6 string.
+    pub const LENGTH: usize = 45;
+
+    /// Write the lower-case URN form into `buffer`, returning it as a `&str`.
+    pub fn encode_lower<'buf>(&self, buffer: &'buf mut [u8; Self::LENGTH]) -> &'buf str {
+        buffer[..9].copy_from_slice(b"urn:uuid:");
+        encode_hyphenated(&self.0, &mut buffer[9..], HEX_LOWER);
+        str::from_utf8(&buffer[..]).unwrap()
+    }
+
+    /// Write the upper-case URN form into `buffer`, returning it as a `&str`.
+    pub fn encode_upper<'buf>(&self, buffer: &'buf mut [u8; Self::LENGTH]) -> &'buf str {
+        buffer[..9].copy_from_slice(b"urn:uuid:");
+        encode_hyphenated(&self.0, &mut buffer[9..], HEX_UPPER);
+        str::from_utf8(&buffer[..]).unwrap()
+    }
+}
+
+impl Braced {
+    /// The length of a braced UUIDv6 string.
+    pub const LENGTH: usize = 38;
+
+    /// Write the lower-case braced form into `buffer`, returning it as a `&str`.
+    pub fn encode_lower<'buf>(&self, buffer: &'buf mut [u8; Self::LENGTH]) -> &'buf str {
+        buffer[0] = b'{';
+        buffer[Self::LENGTH - 1] = b'}';
+        encode_hyphenated(&self.0, &mut buffer[1..Self::LENGTH - 1], HEX_LOWER);
+        str::from_utf8(&buffer[..]).unwrap()
+    }
+
+    /// Write the upper-case braced form into `buffer`, returning it as a `&str`.
+    pub fn encode_upper<'buf>(&self, buffer: &'buf mut [u8; Self::LENGTH]) -> &'buf str {
+        buffer[0] = b'{';
+        buffer[Self::LENGTH - 1] = b'}';
+        encode_hyphenated(&self.0, &mut buffer[1..Self::LENGTH - 1], HEX_UPPER);
+        str::from_utf8(&buffer[..]).unwrap()
+    }
+}
+
+impl fmt::Display for Simple {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut buffer = [0u8; Self::LENGTH];
+        f.write_str(self.encode_lower(&mut buffer))
+    }
+}
+
+impl fmt::Display for Hyphenated {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut buffer = [0u8; Self::LENGTH];
+        f.write_str(self.encode_lower(&mut buffer))
+    }
+}
+
+impl fmt::Display for Urn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut buffer = [0u8; Self::LENGTH];
+        f.write_str(self.encode_lower(&mut buffer))
+    }
+}
+
+impl fmt::Display for Braced {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut buffer = [0u8; Self::LENGTH];
+        f.write_str(self.encode_lower(&mut buffer))
+    }
+}
+
+impl RawUUIDv6 {
+    /// Return a zero-allocation formatter for the simple (no hyphens) form.
+    pub fn simple(&self) -> Simple {
+        Simple(self.as_bytes())
+    }
+
+    /// Return a zero-allocation formatter for the hyphenated form.
+    pub fn hyphenated(&self) -> Hyphenated {
+        Hyphenated::from_bytes(self.as_bytes())
+    }
+
+    /// Return a zero-allocation formatter for the URN form.
+    pub fn urn(&self) -> Urn {
+        Urn(self.as_bytes())
+    }
+
+    /// Return a zero-allocation formatter for the braced form.
+    pub fn braced(&self) -> Braced {
+        Braced(self.as_bytes())
+    }
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_adapters_round_trip() {
+    use crate::Node;
+
+    let node = Node::new();
+    let raw = node.uuidv6_raw();
+    let bytes = raw.as_bytes();
+
+    let mut buf = [0u8; Simple::LENGTH];
+    debug_assert_eq!(raw.simple().encode_lower(&mut buf).len(), 32);
+
+    let mut buf = [0u8; Hyphenated::LENGTH];
+    let hyphenated = raw.hyphenated().encode_lower(&mut buf).to_string();
+    debug_assert_eq!(
+        crate::RawUUIDv6::parse_str(&hyphenated).unwrap().as_bytes(),
+        bytes
+    );
+
+    let mut buf = [0u8; Urn::LENGTH];
+    debug_assert!(raw.urn().encode_lower(&mut buf).starts_with("urn:uuid:"));
+
+    let mut buf = [0u8; Braced::LENGTH];
+    let braced = raw.braced().encode_lower(&mut buf);
+    debug_assert!(braced.starts_with('{') && braced.ends_with('}'));
+}
@@ -1,15 +1,78 @@
-use std::time::{SystemTime, UNIX_EPOCH};
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+mod fmt;
+#[cfg(feature = "serde")]
+mod serde_support;
+mod timestamp;
+#[cfg(feature = "std")]
+mod v7;
+
+pub use fmt::{Braced, Hyphenated, Simple, Urn};
+pub use timestamp::{ClockSequence, Context};
+#[cfg(feature = "std")]
+pub use v7::{RawUUIDv7, RawUUIDv7Iterator, UUIDv7, UUIDv7Iterator};
+
+#[cfg(feature = "std")]
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::{borrow::ToOwned, string::String};
+
+/// Offset, in 100-ns ticks, between the Gregorian epoch (1582-10-15) and
+/// the Unix epoch (1970-01-01). Kept in sync with [`RawUUIDv6::new`].
+#[cfg(feature = "std")]
+const GREGORIAN_EPOCH_OFFSET_100NS: u64 = 122192928000000000;
+
+fn hex_value(c: u8) -> Option<u8> {
+    match c {
+        b'0'..=b'9' => Some(c - b'0'),
+        b'a'..=b'f' => Some(c - b'a' + 10),
+        b'A'..=b'F' => Some(c - b'A' + 10),
+        _ => None,
+    }
+}
+
+fn hex_parse(out: &mut [u8], hex: &[u8]) -> Result<(), ParseError> {
+    for (i, chunk) in hex.chunks(2).enumerate() {
+        let hi = hex_value(chunk[0]).ok_or(ParseError::InvalidCharacter)?;
+        let lo = hex_value(chunk[1]).ok_or(ParseError::InvalidCharacter)?;
+        out[i] = (hi << 4) | lo;
+    }
+    Ok(())
+}
 
-fn hex_format(out: &mut [u8], bin: &[u8]) {
-    const HEX_CHARS: &[u8; 16] = b"0123456789abcdef";
-    let mut j = 0;
-    for b in bin {
-        out[j] = HEX_CHARS[(b >> 4) as usize];
-        out[j + 1] = HEX_CHARS[(b & 0x0f) as usize];
-        j += 2;
+/// An error returned when a string or byte array doesn't represent a valid UUIDv6.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ParseError {
+    /// The string doesn't have the expected 36-byte `8-4-4-4-12` length.
+    InvalidLength,
+    /// A hyphen was missing at one of the four separator positions. A hyphen
+    /// anywhere else is reported as [`ParseError::InvalidCharacter`].
+    InvalidHyphenPosition,
+    /// A non-hexadecimal character was found where hex digits were expected.
+    InvalidCharacter,
+    /// The version nibble (high nibble of byte 6) wasn't `6`.
+    InvalidVersion,
+}
+
+impl core::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let msg = match self {
+            ParseError::InvalidLength => "invalid length",
+            ParseError::InvalidHyphenPosition => "invalid hyphen position",
+            ParseError::InvalidCharacter => "invalid character",
+            ParseError::InvalidVersion => "invalid version",
+        };
+        f.write_str(msg)
     }
 }
 
+#[cfg(feature = "std")]
+impl std::error::Error for ParseError {}
+
 /// A 6 bytes spatially unique identifier.
 #[derive(Default, Debug, Copy, Clone, Hash, Eq, PartialEq, PartialOrd, Ord)]
 pub struct Node {
@@ -29,12 +92,42 @@ impl Node {
         Node { node_id: *bytes }
     }
 
-    /// Create a standard UUIDv6 base object
+    /// Create a node identifier from a MAC address
+    pub fn from_mac(mac: &[u8; 6]) -> Self {
+        Node { node_id: *mac }
+    }
+
+    /// Create a node identifier from the MAC address of a real network interface
+    #[cfg(feature = "mac_address")]
+    pub fn from_interface() -> Result<Self, mac_address::MacAddressError> {
+        let mac =
+            mac_address::get_mac_address()?.ok_or(mac_address::MacAddressError::InternalError)?;
+        Ok(Node::from_mac(&mac.bytes()))
+    }
+
+    /// Create a random node identifier with the multicast bit set, so it can
+    /// never collide with a real burned-in MAC address. This is the
+    /// recommended way to generate a node identifier when no hardware
+    /// address is available.
+    pub fn random_multicast() -> Self {
+        let mut node = Self::new();
+        node.node_id[0] |= 0x01;
+        node
+    }
+
+    /// Return the node identifier as a byte array
+    pub fn as_bytes(&self) -> &[u8; 6] {
+        &self.node_id
+    }
+
+    /// Create a standard UUIDv6 base object, using the system clock
+    #[cfg(feature = "std")]
     pub fn uuidv6(&self) -> UUIDv6 {
         UUIDv6::new(self)
     }
 
-    /// Create a raw UUIDv6 base object - Raw UUIDv6 is a 16 byte binary array, not a string
+    /// Create a raw UUIDv6 base object, using the system clock - Raw UUIDv6 is a 16 byte binary array, not a string
+    #[cfg(feature = "std")]
     pub fn uuidv6_raw(&self) -> RawUUIDv6 {
         RawUUIDv6::new(self)
     }
@@ -50,34 +143,78 @@ pub struct RawUUIDv6 {
 }
 
 /// A regular UUIDv6 is a 36 bytes string
+#[cfg(feature = "alloc")]
 #[derive(Default, Debug, Hash, Eq, PartialEq, PartialOrd, Ord)]
 pub struct UUIDv6 {
     raw: RawUUIDv6,
 }
 
 impl RawUUIDv6 {
-    /// Create a new UUIDv6 base object
+    /// Create a new UUIDv6 base object, using the system clock
+    #[cfg(feature = "std")]
     pub fn new(node: &Node) -> RawUUIDv6 {
         let ts = ((SystemTime::now()
             .duration_since(UNIX_EPOCH)
             .expect("Time went backwards")
             .as_nanos()
-            .checked_add(1221929280000000)
+            .checked_add(u128::from(GREGORIAN_EPOCH_OFFSET_100NS) * 100)
             .expect("Time is completely off"))
             / 100) as u64;
+        Self::from_timestamp(ts, node)
+    }
+
+    /// Create a new UUIDv6 base object from a caller-supplied 60-bit tick
+    /// count (100-ns ticks since the Gregorian epoch), for use where no
+    /// system clock is available.
+    pub fn from_timestamp(ticks_100ns: u64, node: &Node) -> RawUUIDv6 {
         let mut x = [0u8; 2];
         getrandom::getrandom(&mut x).unwrap();
         let initial_counter = u16::from_be_bytes(x);
+        RawUUIDv6::from_parts(ticks_100ns, initial_counter, node)
+    }
+
+    /// Build a `RawUUIDv6` directly from its parts, without drawing from the
+    /// system's random source.
+    pub(crate) fn from_parts(ticks_100ns: u64, counter: u16, node: &Node) -> RawUUIDv6 {
         RawUUIDv6 {
-            ts,
-            counter: initial_counter,
-            initial_counter,
+            ts: ticks_100ns,
+            counter,
+            initial_counter: counter,
             node: *node,
         }
     }
 
     /// Return the next UUIDv6 as bytes
     pub fn create(&mut self) -> [u8; 16] {
+        let buf = self.as_bytes();
+
+        self.counter = self.counter.wrapping_add(1);
+        if self.counter == self.initial_counter {
+            self.reseed();
+        };
+
+        buf
+    }
+
+    /// Re-seed the counter on wrap. With the system clock available, this
+    /// also refreshes the timestamp, matching what a fresh call to `new`
+    /// would produce; without it, only the counter is re-seeded.
+    fn reseed(&mut self) {
+        #[cfg(feature = "std")]
+        {
+            *self = Self::new(&self.node);
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            let mut x = [0u8; 2];
+            getrandom::getrandom(&mut x).unwrap();
+            self.initial_counter = u16::from_be_bytes(x);
+            self.counter = self.initial_counter;
+        }
+    }
+
+    /// Return the current value as bytes, without advancing the counter.
+    pub(crate) fn as_bytes(&self) -> [u8; 16] {
         let mut buf = [0u8; 16];
         let ts = self.ts;
         buf[0..8].copy_from_slice(&(ts << 4).to_be_bytes());
@@ -85,17 +222,81 @@ impl RawUUIDv6 {
         buf[6..8].copy_from_slice(&x.to_be_bytes());
 
         buf[8..10].copy_from_slice(&self.counter.to_be_bytes());
-        self.counter = self.counter.wrapping_add(1);
-        if self.counter == self.initial_counter {
-            *self = Self::new(&self.node);
-        };
-
         buf[10..].copy_from_slice(&self.node.node_id);
         buf
     }
+
+    /// Decompose a raw 16-byte UUIDv6 back into its fields.
+    pub fn from_bytes(bytes: [u8; 16]) -> Result<RawUUIDv6, ParseError> {
+        if bytes[6] >> 4 != 6 {
+            return Err(ParseError::InvalidVersion);
+        }
+
+        let mut high = [0u8; 8];
+        high[2..8].copy_from_slice(&bytes[0..6]);
+        let high = u64::from_be_bytes(high);
+        let low12 = (((bytes[6] & 0x0f) as u64) << 8) | bytes[7] as u64;
+        let ts = (high << 12) | low12;
+
+        let counter = u16::from_be_bytes([bytes[8], bytes[9]]);
+        let mut node_id = [0u8; 6];
+        node_id.copy_from_slice(&bytes[10..16]);
+
+        Ok(RawUUIDv6 {
+            ts,
+            counter,
+            initial_counter: counter,
+            node: Node::from_bytes(&node_id),
+        })
+    }
+
+    /// Parse a hyphenated `8-4-4-4-12` UUIDv6 string back into its fields.
+    pub fn parse_str(s: &str) -> Result<RawUUIDv6, ParseError> {
+        let s = s.as_bytes();
+        if s.len() != 36 {
+            return Err(ParseError::InvalidLength);
+        }
+        if s[8] != b'-' || s[13] != b'-' || s[18] != b'-' || s[23] != b'-' {
+            return Err(ParseError::InvalidHyphenPosition);
+        }
+
+        let mut bytes = [0u8; 16];
+        hex_parse(&mut bytes[0..4], &s[0..8])?;
+        hex_parse(&mut bytes[4..6], &s[9..13])?;
+        hex_parse(&mut bytes[6..8], &s[14..18])?;
+        hex_parse(&mut bytes[8..10], &s[19..23])?;
+        hex_parse(&mut bytes[10..16], &s[24..36])?;
+
+        RawUUIDv6::from_bytes(bytes)
+    }
+
+    /// Return the point in time the UUID was generated at, or `None` if the
+    /// 60-bit tick field lies before the Unix epoch or is too large to
+    /// represent as a `SystemTime` (e.g. a crafted or corrupted UUID rather
+    /// than one this crate generated).
+    #[cfg(feature = "std")]
+    pub fn timestamp(&self) -> Option<SystemTime> {
+        let ns_since_gregorian_epoch = u128::from(self.ts) * 100;
+        let ns_since_unix_epoch = ns_since_gregorian_epoch
+            .checked_sub(u128::from(GREGORIAN_EPOCH_OFFSET_100NS) * 100)?;
+        let ns_since_unix_epoch = u64::try_from(ns_since_unix_epoch).ok()?;
+        Some(UNIX_EPOCH + Duration::from_nanos(ns_since_unix_epoch))
+    }
+
+    /// Return the node identifier the UUID was generated with.
+    pub fn node(&self) -> Node {
+        self.node
+    }
+
+    /// Return the counter value the UUID was generated with.
+    pub fn counter(&self) -> u16 {
+        self.counter
+    }
 }
 
+#[cfg(feature = "alloc")]
 impl UUIDv6 {
+    #[cfg(feature = "std")]
     pub fn new(node: &Node) -> Self {
         UUIDv6 {
             raw: RawUUIDv6::new(node),
@@ -104,21 +305,10 @@ impl UUIDv6 {
 
     /// Return the next UUIDv6 string
     pub fn create(&mut self) -> String {
-        let buf = self.raw.create();
-
-        let mut out = [0u8; 4 + 32];
-        out[8] = b'-';
-        out[13] = b'-';
-        out[18] = b'-';
-        out[23] = b'-';
-
-        hex_format(&mut out[0..], &buf[0..4]);
-        hex_format(&mut out[9..], &buf[4..6]);
-        hex_format(&mut out[14..], &buf[6..8]);
-        hex_format(&mut out[19..], &buf[8..10]);
-        hex_format(&mut out[24..], &buf[10..]);
-
-        String::from_utf8_lossy(&out).into_owned()
+        let mut buf = [0u8; Hyphenated::LENGTH];
+        Hyphenated::from_bytes(self.raw.create())
+            .encode_lower(&mut buf)
+            .to_owned()
     }
 }
 
@@ -144,11 +334,13 @@ impl IntoIterator for RawUUIDv6 {
     }
 }
 
+#[cfg(feature = "alloc")]
 #[derive(Default, Debug, Hash, Eq, PartialEq, PartialOrd, Ord)]
 pub struct UUIDv6Iterator {
     uuid: UUIDv6,
 }
 
+#[cfg(feature = "alloc")]
 impl Iterator for UUIDv6Iterator {
     type Item = String;
 
@@ -157,6 +349,7 @@ impl Iterator for UUIDv6Iterator {
     }
 }
 
+#[cfg(feature = "alloc")]
 impl IntoIterator for UUIDv6 {
     type IntoIter = UUIDv6Iterator;
     type Item = String;
@@ -167,6 +360,7 @@ impl IntoIterator for UUIDv6 {
 }
 
 #[test]
+#[cfg(feature = "std")]
 fn test() {
     let node = Node::new();
 
@@ -181,6 +375,62 @@ fn test() {
 }
 
 #[test]
+#[cfg(feature = "std")]
+fn test_parse_str_round_trip() {
+    let node = Node::new();
+    let raw = node.uuidv6_raw();
+    let counter = raw.counter;
+
+    let mut uuid = UUIDv6 { raw };
+    let s = uuid.create();
+
+    let parsed = RawUUIDv6::parse_str(&s).expect("valid UUIDv6");
+    debug_assert_eq!(parsed.node(), node);
+    debug_assert_eq!(parsed.counter(), counter);
+
+    let now = SystemTime::now();
+    let ts = parsed.timestamp().expect("timestamp in range");
+    let delta = now
+        .duration_since(ts)
+        .or_else(|_| ts.duration_since(now))
+        .expect("clocks didn't move backwards");
+    debug_assert!(delta < Duration::from_secs(5));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_timestamp_rejects_near_max_ticks() {
+    let parsed =
+        RawUUIDv6::parse_str("ffffffff-ffff-6fff-0000-000000000000").expect("valid UUIDv6");
+    debug_assert_eq!(parsed.timestamp(), None);
+}
+
+#[test]
+fn test_parse_str_rejects_garbage() {
+    debug_assert_eq!(
+        RawUUIDv6::parse_str("not-a-uuid"),
+        Err(ParseError::InvalidLength)
+    );
+    debug_assert_eq!(
+        RawUUIDv6::parse_str("00000000x0000-0000-0000-000000000000"),
+        Err(ParseError::InvalidHyphenPosition)
+    );
+    debug_assert_eq!(
+        RawUUIDv6::parse_str("00000000-0000x0000-0000-000000000000"),
+        Err(ParseError::InvalidHyphenPosition)
+    );
+    debug_assert_eq!(
+        RawUUIDv6::parse_str("0000000g-0000-6000-0000-000000000000"),
+        Err(ParseError::InvalidCharacter)
+    );
+    debug_assert_eq!(
+        RawUUIDv6::parse_str("00000000-0000-1000-0000-000000000000"),
+        Err(ParseError::InvalidVersion)
+    );
+}
+
+#[test]
+#[cfg(feature = "std")]
 fn test_raw() {
     let node = Node::new();
 
@@ -193,3 +443,16 @@ fn test_raw() {
     debug_assert_ne!(uid_2, uid_3);
     debug_assert_ne!(uid_3, uid_1);
 }
+
+#[test]
+fn test_node_from_mac() {
+    let mac = [0x08, 0x00, 0x27, 0x12, 0x34, 0x56];
+    let node = Node::from_mac(&mac);
+    debug_assert_eq!(node.as_bytes(), &mac);
+}
+
+#[test]
+fn test_node_random_multicast() {
+    let node = Node::random_multicast();
+    debug_assert_eq!(node.as_bytes()[0] & 0x01, 0x01);
+}
@@ -0,0 +1,102 @@
+#[cfg(feature = "alloc")]
+use crate::UUIDv6;
+use crate::{Hyphenated, RawUUIDv6};
+use core::fmt;
+use serde::de::{Error, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+impl Serialize for RawUUIDv6 {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            let mut buf = [0u8; Hyphenated::LENGTH];
+            serializer.serialize_str(self.hyphenated().encode_lower(&mut buf))
+        } else {
+            serializer.serialize_bytes(&self.as_bytes())
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Serialize for UUIDv6 {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.raw.serialize(serializer)
+    }
+}
+
+struct RawUUIDv6Visitor;
+
+impl<'de> Visitor<'de> for RawUUIDv6Visitor {
+    type Value = RawUUIDv6;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a hyphenated UUIDv6 string or a 16-byte array")
+    }
+
+    fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+        RawUUIDv6::parse_str(v).map_err(Error::custom)
+    }
+
+    fn visit_bytes<E: Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        let bytes: [u8; 16] = v
+            .try_into()
+            .map_err(|_| Error::invalid_length(v.len(), &self))?;
+        RawUUIDv6::from_bytes(bytes).map_err(Error::custom)
+    }
+}
+
+impl<'de> Deserialize<'de> for RawUUIDv6 {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(RawUUIDv6Visitor)
+        } else {
+            deserializer.deserialize_bytes(RawUUIDv6Visitor)
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'de> Deserialize<'de> for UUIDv6 {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(UUIDv6 {
+            raw: RawUUIDv6::deserialize(deserializer)?,
+        })
+    }
+}
+
+#[test]
+fn test_human_readable_round_trips_as_hyphenated_string() {
+    use crate::Node;
+    use serde_test::{assert_tokens, Configure, Token};
+
+    let node = Node::from_bytes(&[1, 2, 3, 4, 5, 6]);
+    let raw = RawUUIDv6::with_context(0x1234_5678_9abc, &node, &7u16);
+
+    assert_tokens(
+        &raw.readable(),
+        &[Token::Str("00012345-6789-6abc-0007-010203040506")],
+    );
+}
+
+#[test]
+fn test_compact_round_trips_as_bytes() {
+    use crate::Node;
+    use serde_test::{assert_tokens, Configure, Token};
+
+    let node = Node::from_bytes(&[1, 2, 3, 4, 5, 6]);
+    let raw = RawUUIDv6::with_context(0x1234_5678_9abc, &node, &7u16);
+
+    assert_tokens(
+        &raw.compact(),
+        &[Token::Bytes(&[
+            0x00, 0x01, 0x23, 0x45, 0x67, 0x89, 0x6a, 0xbc, 0x00, 0x07, 0x01, 0x02, 0x03, 0x04,
+            0x05, 0x06,
+        ])],
+    );
+}
+
+#[test]
+fn test_deserialize_rejects_invalid_string() {
+    use serde_test::{assert_de_tokens_error, Readable, Token};
+
+    assert_de_tokens_error::<Readable<RawUUIDv6>>(&[Token::Str("not-a-uuid")], "invalid length");
+}
@@ -0,0 +1,197 @@
+//! Pluggable counter/timestamp coordination for [`RawUUIDv6::with_context`].
+
+use crate::{Node, RawUUIDv6};
+use core::cell::UnsafeCell;
+use core::hint;
+use core::sync::atomic::{AtomicBool, Ordering};
+
+/// Abstracts how the counter used in a UUIDv6 is obtained for a given tick count.
+///
+/// Implementing this directly enables deterministic generation in tests (by
+/// always returning a fixed counter), while [`Context`] implements it to keep
+/// generation strictly increasing even across multiple generators that share
+/// a single node.
+pub trait ClockSequence {
+    /// The type of sequence returned by this counter.
+    type Output;
+
+    /// Get the next value in the sequence, given the 60-bit tick count
+    /// (100-ns ticks since the Gregorian epoch) of the UUID being generated.
+    fn generate_sequence(&self, ticks_100ns: u64) -> Self::Output;
+}
+
+/// A fixed counter value, useful for deterministic generation in tests.
+impl ClockSequence for u16 {
+    type Output = u16;
+
+    fn generate_sequence(&self, _ticks_100ns: u64) -> Self::Output {
+        *self
+    }
+}
+
+// The full 60-bit tick count plus a 16-bit counter don't fit losslessly in
+// a single atomic word, so they can't be updated together with one CAS.
+// Guarding both behind a tiny spinlock (rather than two independent atomics)
+// keeps the "reset the counter exactly when the tick advances" step a single
+// critical section, with no window where another thread can observe the new
+// tick but the stale counter, or vice versa. `no_std` rules out `std::sync::Mutex`.
+struct SpinLock<T> {
+    locked: AtomicBool,
+    value: UnsafeCell<T>,
+}
+
+unsafe impl<T: Send> Sync for SpinLock<T> {}
+
+impl<T> SpinLock<T> {
+    const fn new(value: T) -> Self {
+        SpinLock {
+            locked: AtomicBool::new(false),
+            value: UnsafeCell::new(value),
+        }
+    }
+
+    fn with<R>(&self, f: impl FnOnce(&mut T) -> R) -> R {
+        while self
+            .locked
+            .compare_exchange_weak(false, true, Ordering::Acquire, Ordering::Relaxed)
+            .is_err()
+        {
+            hint::spin_loop();
+        }
+        // Safety: `locked` was just acquired above and is released before
+        // returning, so this is the only live `&mut T` at any given time.
+        let result = f(unsafe { &mut *self.value.get() });
+        self.locked.store(false, Ordering::Release);
+        result
+    }
+}
+
+/// `UNSEEDED` marks a context that hasn't observed a tick yet, so the first
+/// call can hand out the seeded counter unchanged instead of being mistaken
+/// for a tick advance and reset to 0.
+const UNSEEDED: u64 = u64::MAX;
+
+struct State {
+    last_ts: u64,
+    counter: u16,
+}
+
+/// A thread-safe, shared [`ClockSequence`] that tracks the last-seen
+/// timestamp so that IDs generated within the same 100-ns tick get a
+/// monotonically increasing counter, and the counter resets once the clock
+/// ticks forward.
+///
+/// Sharing one `Context` (e.g. behind an `Arc`) across multiple generators
+/// using the same [`Node`] keeps their output strictly increasing and
+/// sortable, which a plain `RawUUIDv6::new` can't guarantee on its own.
+pub struct Context {
+    state: SpinLock<State>,
+}
+
+impl Context {
+    /// Create a new context, seeded with an initial counter value.
+    pub fn new(initial_counter: u16) -> Self {
+        Context {
+            state: SpinLock::new(State {
+                last_ts: UNSEEDED,
+                counter: initial_counter,
+            }),
+        }
+    }
+}
+
+impl Default for Context {
+    fn default() -> Self {
+        Context::new(0)
+    }
+}
+
+impl core::fmt::Debug for Context {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.state.with(|s| {
+            f.debug_struct("Context")
+                .field("last_ts", &s.last_ts)
+                .field("counter", &s.counter)
+                .finish()
+        })
+    }
+}
+
+impl ClockSequence for Context {
+    type Output = u16;
+
+    fn generate_sequence(&self, ticks_100ns: u64) -> u16 {
+        self.state.with(|s| {
+            let unseeded = s.last_ts == UNSEEDED;
+            let tick_advanced = !unseeded && ticks_100ns > s.last_ts;
+
+            if unseeded || tick_advanced {
+                s.last_ts = ticks_100ns;
+                if tick_advanced {
+                    s.counter = 0;
+                }
+                // If unseeded, the seeded counter is handed out as-is below.
+            }
+
+            let counter = s.counter;
+            s.counter = s.counter.wrapping_add(1);
+            counter
+        })
+    }
+}
+
+impl RawUUIDv6 {
+    /// Create a new UUIDv6 base object, obtaining its counter from a
+    /// [`ClockSequence`] implementation rather than a fresh random value.
+    pub fn with_context<C: ClockSequence<Output = u16>>(
+        ticks_100ns: u64,
+        node: &Node,
+        context: &C,
+    ) -> RawUUIDv6 {
+        let counter = context.generate_sequence(ticks_100ns);
+        RawUUIDv6::from_parts(ticks_100ns, counter, node)
+    }
+}
+
+#[test]
+fn test_context_resets_on_tick_advance() {
+    let context = Context::new(0);
+    debug_assert_eq!(context.generate_sequence(100), 0);
+    debug_assert_eq!(context.generate_sequence(100), 1);
+    debug_assert_eq!(context.generate_sequence(100), 2);
+    debug_assert_eq!(context.generate_sequence(101), 0);
+}
+
+#[test]
+fn test_new_honors_initial_counter_on_first_call() {
+    let context = Context::new(42);
+    debug_assert_eq!(context.generate_sequence(100), 42);
+    debug_assert_eq!(context.generate_sequence(100), 43);
+}
+
+#[test]
+fn test_context_detects_tick_advance_past_48_bits() {
+    let context = Context::new(0);
+    debug_assert_eq!(context.generate_sequence(100), 0);
+
+    // Differs from the previous tick only above bit 48; truncating to the
+    // low 48 bits would make this look identical to the previous tick and
+    // wrongly keep incrementing instead of resetting.
+    let far_future = 100 + (1u64 << 48);
+    debug_assert_eq!(context.generate_sequence(far_future), 0);
+}
+
+#[test]
+fn test_fixed_clock_sequence() {
+    let fixed: u16 = 42;
+    debug_assert_eq!(fixed.generate_sequence(0), 42);
+    debug_assert_eq!(fixed.generate_sequence(12345), 42);
+}
+
+#[test]
+fn test_with_context_is_deterministic() {
+    let node = Node::from_bytes(&[1, 2, 3, 4, 5, 6]);
+    let a = RawUUIDv6::with_context(100, &node, &7u16);
+    let b = RawUUIDv6::with_context(100, &node, &7u16);
+    debug_assert_eq!(a.as_bytes(), b.as_bytes());
+}
@@ -0,0 +1,159 @@
+use crate::Hyphenated;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+fn now_unix_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("Time went backwards")
+        .as_millis() as u64
+}
+
+/// A raw UUIDv7 is a 16 bytes array
+#[derive(Default, Debug, Hash, Eq, PartialEq, PartialOrd, Ord)]
+pub struct RawUUIDv7 {
+    last_ms: u64,
+    counter: u16,
+    initial_counter: u16,
+}
+
+/// A regular UUIDv7 is a 36 bytes string
+#[derive(Default, Debug, Hash, Eq, PartialEq, PartialOrd, Ord)]
+pub struct UUIDv7 {
+    raw: RawUUIDv7,
+}
+
+impl RawUUIDv7 {
+    /// Create a new UUIDv7 base object
+    pub fn new() -> RawUUIDv7 {
+        let last_ms = now_unix_ms();
+        let mut x = [0u8; 2];
+        getrandom::getrandom(&mut x).unwrap();
+        let initial_counter = u16::from_be_bytes(x) & 0x0fff;
+        RawUUIDv7 {
+            last_ms,
+            counter: initial_counter,
+            initial_counter,
+        }
+    }
+
+    /// Return the next UUIDv7 as bytes
+    pub fn create(&mut self) -> [u8; 16] {
+        let ms = now_unix_ms();
+        if ms != self.last_ms {
+            *self = Self::new();
+            self.last_ms = ms;
+        }
+
+        let mut buf = [0u8; 16];
+        buf[0..6].copy_from_slice(&self.last_ms.to_be_bytes()[2..8]);
+
+        let counter = self.counter & 0x0fff;
+        buf[6] = 0x70 | ((counter >> 8) as u8);
+        buf[7] = (counter & 0xff) as u8;
+
+        let mut tail = [0u8; 8];
+        getrandom::getrandom(&mut tail).unwrap();
+        tail[0] = 0x80 | (tail[0] & 0x3f);
+        buf[8..].copy_from_slice(&tail);
+
+        self.counter = self.counter.wrapping_add(1) & 0x0fff;
+        if self.counter == self.initial_counter {
+            *self = Self::new();
+        }
+
+        buf
+    }
+}
+
+impl UUIDv7 {
+    /// Create a new UUIDv7 base object
+    pub fn new() -> Self {
+        UUIDv7 {
+            raw: RawUUIDv7::new(),
+        }
+    }
+
+    /// Return the next UUIDv7 string
+    pub fn create(&mut self) -> String {
+        let mut out = [0u8; Hyphenated::LENGTH];
+        Hyphenated::from_bytes(self.raw.create())
+            .encode_lower(&mut out)
+            .to_owned()
+    }
+}
+
+#[derive(Default, Debug, Hash, Eq, PartialEq, PartialOrd, Ord)]
+pub struct RawUUIDv7Iterator {
+    uuid: RawUUIDv7,
+}
+
+impl Iterator for RawUUIDv7Iterator {
+    type Item = [u8; 16];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.uuid.create())
+    }
+}
+
+impl IntoIterator for RawUUIDv7 {
+    type IntoIter = RawUUIDv7Iterator;
+    type Item = [u8; 16];
+
+    fn into_iter(self) -> Self::IntoIter {
+        RawUUIDv7Iterator { uuid: self }
+    }
+}
+
+#[derive(Default, Debug, Hash, Eq, PartialEq, PartialOrd, Ord)]
+pub struct UUIDv7Iterator {
+    uuid: UUIDv7,
+}
+
+impl Iterator for UUIDv7Iterator {
+    type Item = String;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.uuid.create())
+    }
+}
+
+impl IntoIterator for UUIDv7 {
+    type IntoIter = UUIDv7Iterator;
+    type Item = String;
+
+    fn into_iter(self) -> Self::IntoIter {
+        UUIDv7Iterator { uuid: self }
+    }
+}
+
+#[test]
+fn test() {
+    let mut st = UUIDv7::new().into_iter();
+
+    let uid_1 = st.next();
+    let uid_2 = st.next();
+    let uid_3 = st.next();
+    debug_assert_ne!(uid_1, uid_2);
+    debug_assert_ne!(uid_2, uid_3);
+    debug_assert_ne!(uid_3, uid_1);
+}
+
+#[test]
+fn test_raw() {
+    let mut st = RawUUIDv7::new().into_iter();
+
+    let uid_1 = st.next();
+    let uid_2 = st.next();
+    let uid_3 = st.next();
+    debug_assert_ne!(uid_1, uid_2);
+    debug_assert_ne!(uid_2, uid_3);
+    debug_assert_ne!(uid_3, uid_1);
+}
+
+#[test]
+fn test_version_and_variant_nibbles() {
+    let mut raw = RawUUIDv7::new();
+    let buf = raw.create();
+    debug_assert_eq!(buf[6] >> 4, 7);
+    debug_assert_eq!(buf[8] >> 6, 0b10);
+}